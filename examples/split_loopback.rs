@@ -0,0 +1,62 @@
+// Loopback self-test for split reader/writer halves.
+// Runs a writer thread and a reader thread on the two halves of one port whose
+// TX is looped back to its RX (e.g. a loopback adapter or a TX-RX jumper)
+// and reports throughput.
+// Usage: cargo run --example split_loopback <PORT> [bytes]
+
+use gdserial::split;
+use std::env;
+use std::time::{Duration, Instant};
+
+fn main() {
+    let port_name = env::args().nth(1).expect("Usage: program <PORT> [bytes]");
+    let total_bytes: usize = env::args()
+        .nth(2)
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(65536);
+
+    println!("Opening {}...", port_name);
+
+    let port = serialport::new(&port_name, 115200)
+        .timeout(Duration::from_millis(1000))
+        .open()
+        .expect("Failed to open port");
+
+    let (mut reader, mut writer) = split::split(port.as_ref()).expect("Failed to split port");
+
+    let writer_thread = std::thread::spawn(move || {
+        let chunk = vec![0xABu8; 256];
+        let mut sent = 0;
+        while sent < total_bytes {
+            let n = (total_bytes - sent).min(chunk.len());
+            writer.write_all(&chunk[..n]).expect("write failed");
+            sent += n;
+        }
+    });
+
+    let start = Instant::now();
+    let mut received = 0usize;
+    let mut buffer = [0u8; 256];
+
+    while received < total_bytes {
+        match reader.read(&mut buffer) {
+            Ok(n) => received += n,
+            Err(ref e) if e.kind() == std::io::ErrorKind::TimedOut => continue,
+            Err(e) => {
+                eprintln!("Read error: {}", e);
+                break;
+            }
+        }
+    }
+
+    writer_thread.join().expect("writer thread panicked");
+    let elapsed = start.elapsed();
+
+    println!(
+        "Received {} of {} bytes in {:.2?} ({:.1} KB/s)",
+        received,
+        total_bytes,
+        elapsed,
+        (received as f64 / 1024.0) / elapsed.as_secs_f64()
+    );
+}
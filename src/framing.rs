@@ -0,0 +1,212 @@
+//! COBS (Consistent Overhead Byte Stuffing) packet framing.
+//!
+//! Embedded peers often stream binary structs instead of ASCII lines. This module
+//! accumulates an incoming byte stream, splits it on the `0x00` frame delimiter, and
+//! COBS-decodes each segment back into its original payload, with an optional
+//! trailing CRC16 to reject corrupt frames.
+
+const FRAME_DELIMITER: u8 = 0x00;
+
+/// COBS-encode `data` and append the trailing frame delimiter.
+pub fn encode_packet(data: &[u8]) -> Vec<u8> {
+    let mut encoded = cobs_encode(data);
+    encoded.push(FRAME_DELIMITER);
+    encoded
+}
+
+/// COBS-encode `data` without appending a frame delimiter.
+pub fn cobs_encode(data: &[u8]) -> Vec<u8> {
+    let mut output = Vec::with_capacity(data.len() + data.len() / 254 + 1);
+    let mut code_pos = 0;
+    output.push(0); // placeholder, patched in once the run length is known
+    let mut code = 1u8;
+
+    for &byte in data {
+        if byte == 0 {
+            output[code_pos] = code;
+            code_pos = output.len();
+            output.push(0);
+            code = 1;
+        } else {
+            output.push(byte);
+            code += 1;
+            if code == 0xFF {
+                output[code_pos] = code;
+                code_pos = output.len();
+                output.push(0);
+                code = 1;
+            }
+        }
+    }
+    output[code_pos] = code;
+    output
+}
+
+/// COBS-decode a single delimited segment (without its trailing `0x00`) back into
+/// the original payload bytes. Returns `None` on a malformed/truncated segment.
+pub fn cobs_decode(encoded: &[u8]) -> Option<Vec<u8>> {
+    let mut output = Vec::with_capacity(encoded.len());
+    let mut pos = 0;
+
+    while pos < encoded.len() {
+        let code = encoded[pos] as usize;
+        if code == 0 {
+            return None;
+        }
+        pos += 1;
+
+        let run_end = pos + code - 1;
+        if run_end > encoded.len() {
+            return None;
+        }
+        output.extend_from_slice(&encoded[pos..run_end]);
+        pos = run_end;
+
+        if code < 0xFF && pos < encoded.len() {
+            output.push(0);
+        }
+    }
+
+    Some(output)
+}
+
+/// CRC16-CCITT (polynomial 0x1021, initial value 0xFFFF) over `data`.
+pub fn crc16(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0xFFFF;
+    for &byte in data {
+        crc ^= (byte as u16) << 8;
+        for _ in 0..8 {
+            if crc & 0x8000 != 0 {
+                crc = (crc << 1) ^ 0x1021;
+            } else {
+                crc <<= 1;
+            }
+        }
+    }
+    crc
+}
+
+/// Accumulates bytes from a serial stream and yields complete COBS-framed packets.
+pub struct PacketReader {
+    buffer: Vec<u8>,
+    crc_enabled: bool,
+}
+
+impl PacketReader {
+    /// Create a reader with no CRC validation.
+    pub fn new() -> Self {
+        Self {
+            buffer: Vec::new(),
+            crc_enabled: false,
+        }
+    }
+
+    /// Create a reader that, when `crc_enabled` is true, rejects frames whose
+    /// trailing CRC16 doesn't match.
+    pub fn with_crc(crc_enabled: bool) -> Self {
+        Self {
+            buffer: Vec::new(),
+            crc_enabled,
+        }
+    }
+
+    /// Buffer newly-received bytes for framing.
+    pub fn feed(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    /// Decode and return the next complete packet buffered so far, if any.
+    ///
+    /// Corrupt or CRC-mismatched frames are dropped (`None`) rather than returned.
+    pub fn read_packet(&mut self) -> Option<Vec<u8>> {
+        let delimiter_pos = self.buffer.iter().position(|&b| b == FRAME_DELIMITER)?;
+        let frame: Vec<u8> = self.buffer.drain(..=delimiter_pos).collect();
+        let encoded = &frame[..frame.len() - 1];
+        let mut payload = cobs_decode(encoded)?;
+
+        if self.crc_enabled {
+            if payload.len() < 2 {
+                return None;
+            }
+            let crc_len = payload.len() - 2;
+            let received_crc = u16::from_be_bytes([payload[crc_len], payload[crc_len + 1]]);
+            payload.truncate(crc_len);
+            if crc16(&payload) != received_crc {
+                return None;
+            }
+        }
+
+        Some(payload)
+    }
+
+    /// COBS-encode `data` (appending a CRC16 first if enabled) into a ready-to-send frame.
+    pub fn write_packet(&self, data: &[u8]) -> Vec<u8> {
+        let mut payload = data.to_vec();
+        if self.crc_enabled {
+            let crc = crc16(&payload);
+            payload.extend_from_slice(&crc.to_be_bytes());
+        }
+        encode_packet(&payload)
+    }
+}
+
+impl Default for PacketReader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_arbitrary_payloads() {
+        let cases: &[&[u8]] = &[
+            b"",
+            b"hello",
+            &[0x00, 0x00, 0x00],
+            &[1, 2, 3, 0, 4, 5, 0, 6],
+            &vec![0xAAu8; 512],
+        ];
+
+        for payload in cases {
+            let encoded = cobs_encode(payload);
+            assert!(!encoded.contains(&0x00));
+            let decoded = cobs_decode(&encoded).expect("valid frame decodes");
+            assert_eq!(&decoded, payload);
+        }
+    }
+
+    #[test]
+    fn packet_reader_splits_on_delimiter() {
+        let mut reader = PacketReader::new();
+        let frame = reader.write_packet(b"abc");
+        reader.feed(&frame);
+        assert_eq!(reader.read_packet(), Some(b"abc".to_vec()));
+        assert_eq!(reader.read_packet(), None);
+    }
+
+    #[test]
+    fn packet_reader_buffers_partial_frames() {
+        let mut reader = PacketReader::new();
+        let frame = reader.write_packet(b"partial");
+        reader.feed(&frame[..frame.len() - 1]);
+        assert_eq!(reader.read_packet(), None);
+        reader.feed(&frame[frame.len() - 1..]);
+        assert_eq!(reader.read_packet(), Some(b"partial".to_vec()));
+    }
+
+    #[test]
+    fn crc_rejects_corrupted_frames() {
+        let writer = PacketReader::with_crc(true);
+        let mut frame = writer.write_packet(b"checked");
+        // Flip a payload byte before the trailing delimiter to corrupt the CRC.
+        let idx = frame.len() - 2;
+        frame[idx] ^= 0xFF;
+
+        let mut reader = PacketReader::with_crc(true);
+        reader.feed(&frame);
+        assert_eq!(reader.read_packet(), None);
+    }
+}
@@ -1,11 +1,23 @@
+pub mod framing;
+pub mod protocol;
+pub mod ring;
+pub mod split;
+pub mod timed_read;
+pub mod watch;
+
 use godot::prelude::*;
-use serialport::{SerialPort, SerialPortType, DataBits, Parity, StopBits, FlowControl, ErrorKind};
-use std::time::Duration;
+use serialport::{SerialPort, DataBits, Parity, StopBits, FlowControl, ErrorKind};
+use std::collections::VecDeque;
 use std::io::{self, Read};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
 
 /// Build human-readable USB device name from descriptors
 /// Optimized to minimize allocations by building string directly
-fn get_usb_device_name(vid: u16, pid: u16, manufacturer: &Option<String>, product: &Option<String>) -> String {
+pub(crate) fn get_usb_device_name(vid: u16, pid: u16, manufacturer: &Option<String>, product: &Option<String>) -> String {
     // Extract trimmed manufacturer string if available
     let mfg = manufacturer.as_ref()
         .map(|s| s.trim())
@@ -44,20 +56,29 @@ unsafe impl ExtensionLibrary for GdSerialExtension {}
 const DEFAULT_BAUD_RATE: u32 = 9600;
 const DEFAULT_TIMEOUT_MS: u64 = 1000;
 const READLINE_BUFFER_SIZE: usize = 256;
-const READLINE_INITIAL_CAPACITY: usize = 64;
+const DEFAULT_READER_DELIMITER: u8 = b'\n';
+const DEFAULT_PER_BYTE_TIMEOUT_MULTIPLIER_MS: u64 = 1;
+const DEFAULT_COMMAND_START_MARKER: u8 = b'<';
+const DEFAULT_COMMAND_END_MARKER: u8 = b'>';
+const DEFAULT_PORT_WATCH_POLL_MS: u32 = 500;
+const DEFAULT_READLINE_MAX_LEN: u32 = 4096;
 
 // Static strings for dictionary keys to avoid repeated allocations
 const KEY_PORT_NAME: &str = "port_name";
 const KEY_PORT_TYPE: &str = "port_type";
 const KEY_DEVICE_NAME: &str = "device_name";
+const KEY_VID: &str = "vid";
+const KEY_PID: &str = "pid";
+const KEY_SERIAL_NUMBER: &str = "serial_number";
+const KEY_INTERFACE: &str = "interface";
 
 // Static strings for port types
-const PORT_TYPE_PCI: &str = "PCI";
-const PORT_TYPE_BLUETOOTH: &str = "Bluetooth";
-const PORT_TYPE_UNKNOWN: &str = "Unknown";
-const DEVICE_NAME_PCI: &str = "PCI Serial Port";
-const DEVICE_NAME_BLUETOOTH: &str = "Bluetooth Serial Port";
-const DEVICE_NAME_UNKNOWN: &str = "Unknown Serial Device";
+pub(crate) const PORT_TYPE_PCI: &str = "PCI";
+pub(crate) const PORT_TYPE_BLUETOOTH: &str = "Bluetooth";
+pub(crate) const PORT_TYPE_UNKNOWN: &str = "Unknown";
+pub(crate) const DEVICE_NAME_PCI: &str = "PCI Serial Port";
+pub(crate) const DEVICE_NAME_BLUETOOTH: &str = "Bluetooth Serial Port";
+pub(crate) const DEVICE_NAME_UNKNOWN: &str = "Unknown Serial Device";
 
 // Common error messages
 const ERR_PORT_NOT_OPEN: &str = "Port not open";
@@ -70,6 +91,30 @@ pub struct GdSerial {
     port_name: String,
     baud_rate: u32,
     timeout: Duration,
+    data_bits: DataBits,
+    parity: Parity,
+    stop_bits: StopBits,
+    flow_control: FlowControl,
+    reader_handle: Option<JoinHandle<()>>,
+    reader_stop: Option<Arc<AtomicBool>>,
+    reader_rx: Option<Receiver<String>>,
+    reader_queue: VecDeque<String>,
+    reader_delimiter: u8,
+    split_reader: Option<split::SerialReader>,
+    split_writer: Option<split::SerialWriter>,
+    per_byte_timeout_multiplier: Duration,
+    protocol: protocol::CommandProtocol,
+    listen_handle: Option<JoinHandle<()>>,
+    listen_stop: Option<Arc<AtomicBool>>,
+    listen_rx: Option<Receiver<Vec<u8>>>,
+    line_framing_enabled: bool,
+    line_accum: Vec<u8>,
+    watch_handle: Option<JoinHandle<()>>,
+    watch_stop: Option<Arc<AtomicBool>>,
+    watch_rx: Option<Receiver<watch::PortEvent>>,
+    auto_reopen: bool,
+    frame_buffer: ring::RingBuffer,
+    line_ending: ring::LineEnding,
 }
 
 #[godot_api]
@@ -81,12 +126,56 @@ impl IRefCounted for GdSerial {
             port_name: String::new(),
             baud_rate: DEFAULT_BAUD_RATE,
             timeout: Duration::from_millis(DEFAULT_TIMEOUT_MS),
+            data_bits: DataBits::Eight,
+            parity: Parity::None,
+            stop_bits: StopBits::One,
+            flow_control: FlowControl::None,
+            reader_handle: None,
+            reader_stop: None,
+            reader_rx: None,
+            reader_queue: VecDeque::new(),
+            reader_delimiter: DEFAULT_READER_DELIMITER,
+            split_reader: None,
+            split_writer: None,
+            per_byte_timeout_multiplier: Duration::from_millis(DEFAULT_PER_BYTE_TIMEOUT_MULTIPLIER_MS),
+            protocol: protocol::CommandProtocol::new(
+                DEFAULT_COMMAND_START_MARKER,
+                DEFAULT_COMMAND_END_MARKER,
+                b"OK".to_vec(),
+            ),
+            listen_handle: None,
+            listen_stop: None,
+            listen_rx: None,
+            line_framing_enabled: false,
+            line_accum: Vec::new(),
+            watch_handle: None,
+            watch_stop: None,
+            watch_rx: None,
+            auto_reopen: false,
+            frame_buffer: ring::RingBuffer::new(),
+            line_ending: ring::LineEnding::CrLf,
         }
     }
 }
 
 #[godot_api]
 impl GdSerial {
+    /// Emitted from `poll()` with each raw chunk the listener thread received.
+    #[signal]
+    fn data_received(bytes: PackedByteArray);
+
+    /// Emitted from `poll()` for each complete line when line framing is enabled.
+    #[signal]
+    fn line_received(text: GString);
+
+    /// Emitted from `poll()` when the port watcher notices a new port appear.
+    #[signal]
+    fn port_connected(info: Dictionary);
+
+    /// Emitted from `poll()` when the port watcher notices a port disappear.
+    #[signal]
+    fn port_disconnected(port_name: GString);
+
     /// Check if an IO error kind indicates device disconnection
     #[inline]
     fn is_disconnection_io_kind(kind: io::ErrorKind) -> bool {
@@ -136,25 +225,22 @@ impl GdSerial {
                     let mut port_info = Dictionary::new();
                     port_info.set(KEY_PORT_NAME, port.port_name.as_str());
 
-                    let (port_type, device_name) = match &port.port_type {
-                        SerialPortType::UsbPort(usb_info) => {
-                            let port_type = format!("USB - VID: {:04X}, PID: {:04X}",
-                                   usb_info.vid, usb_info.pid);
-                            let device_name = get_usb_device_name(
-                                usb_info.vid,
-                                usb_info.pid,
-                                &usb_info.manufacturer,
-                                &usb_info.product
-                            );
-                            (port_type, device_name)
-                        }
-                        SerialPortType::PciPort => (PORT_TYPE_PCI.to_string(), DEVICE_NAME_PCI.to_string()),
-                        SerialPortType::BluetoothPort => (PORT_TYPE_BLUETOOTH.to_string(), DEVICE_NAME_BLUETOOTH.to_string()),
-                        SerialPortType::Unknown => (PORT_TYPE_UNKNOWN.to_string(), DEVICE_NAME_UNKNOWN.to_string()),
-                    };
+                    let (port_type, device_name) = watch::classify_port(port);
 
                     port_info.set(KEY_PORT_TYPE, port_type.as_str());
                     port_info.set(KEY_DEVICE_NAME, device_name.as_str());
+
+                    if let Some(usb_info) = watch::usb_info(port) {
+                        port_info.set(KEY_VID, usb_info.vid as i32);
+                        port_info.set(KEY_PID, usb_info.pid as i32);
+                        port_info.set(KEY_SERIAL_NUMBER, usb_info.serial_number.as_deref().unwrap_or(""));
+
+                        #[cfg(any(target_os = "linux", target_os = "android"))]
+                        if let Some(interface) = usb_info.interface {
+                            port_info.set(KEY_INTERFACE, interface as i32);
+                        }
+                    }
+
                     ports_dict.set(i as i32, port_info);
                 }
             }
@@ -165,7 +251,41 @@ impl GdSerial {
 
         ports_dict
     }
-    
+
+    /// Scan `available_ports()` for the first USB device matching `vid`/`pid`
+    /// (and `serial_number`, when non-empty), set `port_name` to it, and `open()`.
+    /// Avoids relying on OS-assigned COM/ttyUSB names that shift between reboots.
+    #[func]
+    pub fn open_by_vid_pid(&mut self, vid: u16, pid: u16, serial_number: GString) -> bool {
+        let serial_filter = serial_number.to_string();
+
+        let ports = match serialport::available_ports() {
+            Ok(ports) => ports,
+            Err(e) => {
+                godot_error!("Failed to list ports: {}", e);
+                return false;
+            }
+        };
+
+        let matched = ports.iter().find(|port| match watch::usb_info(port) {
+            Some(usb_info) if usb_info.vid == vid && usb_info.pid == pid => {
+                serial_filter.is_empty() || usb_info.serial_number.as_deref() == Some(serial_filter.as_str())
+            }
+            _ => false,
+        });
+
+        match matched {
+            Some(port) => {
+                self.port_name = port.port_name.clone();
+                self.open()
+            }
+            None => {
+                godot_error!("No USB device found for VID: 0x{:04X}, PID: 0x{:04X}", vid, pid);
+                false
+            }
+        }
+    }
+
     #[func]
     #[inline]
     pub fn set_port(&mut self, port_name: GString) {
@@ -173,9 +293,13 @@ impl GdSerial {
     }
 
     #[func]
-    #[inline]
     pub fn set_baud_rate(&mut self, baud_rate: u32) {
         self.baud_rate = baud_rate;
+        if let Some(port) = &mut self.port {
+            if let Err(e) = port.set_baud_rate(baud_rate) {
+                godot_error!("Failed to apply baud rate to open port: {}", e);
+            }
+        }
     }
 
     #[func]
@@ -183,20 +307,59 @@ impl GdSerial {
     pub fn set_timeout(&mut self, timeout_ms: u32) {
         self.timeout = Duration::from_millis(timeout_ms as u64);
     }
-    
+
+    #[func]
+    pub fn set_data_bits(&mut self, data_bits: u8) {
+        self.data_bits = match data_bits {
+            5 => DataBits::Five,
+            6 => DataBits::Six,
+            7 => DataBits::Seven,
+            _ => DataBits::Eight,
+        };
+    }
+
+    /// `parity`: 0 = None, 1 = Odd, 2 = Even.
+    #[func]
+    pub fn set_parity(&mut self, parity: i32) {
+        self.parity = match parity {
+            1 => Parity::Odd,
+            2 => Parity::Even,
+            _ => Parity::None,
+        };
+    }
+
+    /// `stop_bits`: 1 = One, 2 = Two.
+    #[func]
+    pub fn set_stop_bits(&mut self, stop_bits: i32) {
+        self.stop_bits = match stop_bits {
+            2 => StopBits::Two,
+            _ => StopBits::One,
+        };
+    }
+
+    /// `flow_control`: 0 = None, 1 = Software, 2 = Hardware.
+    #[func]
+    pub fn set_flow_control(&mut self, flow_control: i32) {
+        self.flow_control = match flow_control {
+            1 => FlowControl::Software,
+            2 => FlowControl::Hardware,
+            _ => FlowControl::None,
+        };
+    }
+
     #[func]
     pub fn open(&mut self) -> bool {
         if self.port_name.is_empty() {
             godot_error!("Port name not set");
             return false;
         }
-        
+
         match serialport::new(&self.port_name, self.baud_rate)
             .timeout(self.timeout)
-            .data_bits(DataBits::Eight)
-            .parity(Parity::None)
-            .stop_bits(StopBits::One)
-            .flow_control(FlowControl::None)
+            .data_bits(self.data_bits)
+            .parity(self.parity)
+            .stop_bits(self.stop_bits)
+            .flow_control(self.flow_control)
             .open()
         {
             Ok(port) => {
@@ -323,61 +486,74 @@ impl GdSerial {
         }
     }
     
+    /// `ending`: 0 = LF, 1 = CRLF, 2 = CR, anything else is used directly as a
+    /// custom delimiter byte.
     #[func]
-    pub fn readline(&mut self) -> GString {
+    pub fn set_line_ending(&mut self, ending: i32) {
+        self.line_ending = match ending {
+            0 => ring::LineEnding::Lf,
+            1 => ring::LineEnding::CrLf,
+            2 => ring::LineEnding::Cr,
+            other => ring::LineEnding::Custom(other as u8),
+        };
+    }
+
+    #[func]
+    #[inline]
+    pub fn reset_frame_buffer(&mut self) {
+        self.frame_buffer.clear();
+    }
+
+    /// Read one chunk of at most `READLINE_BUFFER_SIZE` bytes from the port into
+    /// the persistent frame buffer (waiting up to the port timeout if nothing has
+    /// arrived), then scan the buffer for `delimiter`. Returns the frame before the
+    /// delimiter (delimiter excluded) once one is available, or an empty array
+    /// if the frame isn't complete yet - leftover bytes stay buffered for the
+    /// next call instead of being discarded, so a timeout mid-frame can't lose
+    /// data. `max_len` bounds how much undelimited data is kept around.
+    #[func]
+    pub fn read_until(&mut self, delimiter: u8, max_len: u32) -> PackedByteArray {
         match &mut self.port {
             Some(port) => {
-                // Use a buffer for more efficient reading (reduces system calls significantly)
-                let mut line = String::with_capacity(READLINE_INITIAL_CAPACITY);
                 let mut buffer = [0u8; READLINE_BUFFER_SIZE];
-                let mut buffer_pos = 0;
-                let mut buffer_len = 0;
-
-                loop {
-                    // Refill buffer if empty
-                    if buffer_pos >= buffer_len {
-                        match port.read(&mut buffer) {
-                            Ok(0) => break, // No more data
-                            Ok(n) => {
-                                buffer_len = n;
-                                buffer_pos = 0;
-                            }
-                            Err(ref e) if e.kind() == io::ErrorKind::TimedOut => break,
-                            Err(e) => {
-                                if Self::is_disconnection_io_kind(e.kind()) {
-                                    self.handle_potential_io_disconnection(&e);
-                                }
-
-                                if line.is_empty() && e.kind() != io::ErrorKind::WouldBlock {
-                                    godot_error!("Failed to read line: {}", e);
-                                    return GString::new();
-                                } else {
-                                    break;
-                                }
-                            }
-                        }
-                    }
-
-                    // Process buffered data
-                    while buffer_pos < buffer_len {
-                        let ch = buffer[buffer_pos] as char;
-                        buffer_pos += 1;
-
-                        if ch == '\n' {
-                            return GString::from(&line);
-                        } else if ch != '\r' {
-                            line.push(ch);
-                        }
+                match port.read(&mut buffer) {
+                    Ok(n) if n > 0 => self.frame_buffer.push(&buffer[..n]),
+                    Ok(_) => {}
+                    Err(ref e) if e.kind() == io::ErrorKind::TimedOut || e.kind() == io::ErrorKind::WouldBlock => {}
+                    Err(e) => {
+                        self.handle_potential_io_disconnection(&e);
+                        godot_error!("Failed to read from port: {}", e);
                     }
                 }
-
-                GString::from(&line)
             }
             None => {
                 godot_error!("{}", ERR_PORT_NOT_OPEN);
-                GString::new()
+                return PackedByteArray::new();
             }
         }
+
+        match self.frame_buffer.read_until(delimiter, max_len as usize) {
+            Some(frame) => PackedByteArray::from(&frame[..]),
+            None => PackedByteArray::new(),
+        }
+    }
+
+    #[func]
+    pub fn readline(&mut self) -> GString {
+        let delimiter = self.line_ending.delimiter();
+        let frame = self.read_until(delimiter, DEFAULT_READLINE_MAX_LEN);
+        if frame.is_empty() {
+            return GString::new();
+        }
+
+        let text = String::from_utf8_lossy(frame.as_slice()).into_owned();
+        let text = if self.line_ending == ring::LineEnding::CrLf {
+            text.trim_end_matches('\r').to_string()
+        } else {
+            text
+        };
+
+        GString::from(text)
     }
 
     #[func]
@@ -421,4 +597,577 @@ impl GdSerial {
             }
         }
     }
+
+    /// Drain any lines the reader thread has pushed into the channel into the local queue
+    fn drain_reader_channel(&mut self) {
+        if let Some(rx) = &self.reader_rx {
+            while let Ok(line) = rx.try_recv() {
+                self.reader_queue.push_back(line);
+            }
+        }
+    }
+
+    #[func]
+    #[inline]
+    pub fn set_reader_delimiter(&mut self, delimiter: u8) {
+        self.reader_delimiter = delimiter;
+    }
+
+    /// Spawns a reader thread on a `try_clone()` of the port. Mutually exclusive
+    /// with `start_listening()`: both clone the same port and would compete for
+    /// incoming bytes, splitting them nondeterministically between the two.
+    #[func]
+    pub fn start_reader(&mut self) -> bool {
+        if self.reader_handle.is_some() {
+            godot_error!("Reader thread already running");
+            return false;
+        }
+
+        let port = match &self.port {
+            Some(port) => port,
+            None => {
+                godot_error!("{}", ERR_PORT_NOT_OPEN);
+                return false;
+            }
+        };
+
+        let mut reader_port = match port.try_clone() {
+            Ok(cloned) => cloned,
+            Err(e) => {
+                godot_error!("Failed to clone port for reader thread: {}", e);
+                return false;
+            }
+        };
+
+        let (tx, rx) = mpsc::channel();
+        let stop_flag = Arc::new(AtomicBool::new(false));
+        let thread_stop_flag = stop_flag.clone();
+        let delimiter = self.reader_delimiter;
+
+        let handle = std::thread::spawn(move || {
+            let mut ring: Vec<u8> = Vec::new();
+            let mut buffer = [0u8; READLINE_BUFFER_SIZE];
+
+            while !thread_stop_flag.load(Ordering::Relaxed) {
+                match reader_port.read(&mut buffer) {
+                    // A 0-byte read with no error means EOF (e.g. the port was
+                    // unplugged/closed underneath us) - stop instead of busy-spinning.
+                    Ok(0) => break,
+                    Ok(n) => {
+                        ring.extend_from_slice(&buffer[..n]);
+                        while let Some(pos) = ring.iter().position(|&b| b == delimiter) {
+                            let frame: Vec<u8> = ring.drain(..=pos).collect();
+                            let line = String::from_utf8_lossy(&frame[..frame.len() - 1]).into_owned();
+                            if tx.send(line).is_err() {
+                                return;
+                            }
+                        }
+                    }
+                    Err(ref e) if e.kind() == io::ErrorKind::TimedOut || e.kind() == io::ErrorKind::WouldBlock => {
+                        continue;
+                    }
+                    Err(_) => break,
+                }
+            }
+        });
+
+        self.reader_handle = Some(handle);
+        self.reader_rx = Some(rx);
+        self.reader_stop = Some(stop_flag);
+        true
+    }
+
+    #[func]
+    pub fn stop_reader(&mut self) {
+        if let Some(stop_flag) = self.reader_stop.take() {
+            stop_flag.store(true, Ordering::Relaxed);
+        }
+        if let Some(handle) = self.reader_handle.take() {
+            let _ = handle.join();
+        }
+        self.reader_rx = None;
+        self.reader_queue.clear();
+    }
+
+    #[func]
+    pub fn poll_line(&mut self) -> GString {
+        self.drain_reader_channel();
+        match self.reader_queue.pop_front() {
+            Some(line) => GString::from(line),
+            None => GString::new(),
+        }
+    }
+
+    #[func]
+    pub fn available_lines(&mut self) -> u32 {
+        self.drain_reader_channel();
+        self.reader_queue.len() as u32
+    }
+
+    #[func]
+    pub fn try_split(&mut self) -> bool {
+        let port = match &self.port {
+            Some(port) => port.as_ref(),
+            None => {
+                godot_error!("{}", ERR_PORT_NOT_OPEN);
+                return false;
+            }
+        };
+
+        match split::split(port) {
+            Ok((reader, writer)) => {
+                self.split_reader = Some(reader);
+                self.split_writer = Some(writer);
+                true
+            }
+            Err(e) => {
+                godot_error!("Failed to split port: {}", e);
+                false
+            }
+        }
+    }
+
+    #[func]
+    pub fn end_split(&mut self) {
+        self.split_reader = None;
+        self.split_writer = None;
+    }
+
+    #[func]
+    pub fn split_read(&mut self, size: u32) -> PackedByteArray {
+        match &mut self.split_reader {
+            Some(reader) => {
+                let mut buffer = vec![0; size as usize];
+                match reader.read(&mut buffer) {
+                    Ok(bytes_read) => {
+                        buffer.truncate(bytes_read);
+                        PackedByteArray::from(&buffer[..])
+                    }
+                    Err(e) => {
+                        if e.kind() != io::ErrorKind::TimedOut && e.kind() != io::ErrorKind::WouldBlock {
+                            godot_error!("Failed to read from split reader: {}", e);
+                        }
+                        PackedByteArray::new()
+                    }
+                }
+            }
+            None => {
+                godot_error!("Port not split, call try_split() first");
+                PackedByteArray::new()
+            }
+        }
+    }
+
+    #[func]
+    pub fn split_write(&mut self, data: PackedByteArray) -> bool {
+        match &mut self.split_writer {
+            Some(writer) => {
+                let bytes = data.to_vec();
+                match writer.write_all(&bytes).and_then(|_| writer.flush()) {
+                    Ok(_) => true,
+                    Err(e) => {
+                        godot_error!("Failed to write to split writer: {}", e);
+                        false
+                    }
+                }
+            }
+            None => {
+                godot_error!("Port not split, call try_split() first");
+                false
+            }
+        }
+    }
+
+    #[func]
+    #[inline]
+    pub fn set_per_byte_timeout_multiplier(&mut self, multiplier_ms: u32) {
+        self.per_byte_timeout_multiplier = Duration::from_millis(multiplier_ms as u64);
+    }
+
+    /// Read `size` bytes with a length-proportional deadline. When `any_available`
+    /// is `true`, returns as soon as at least one byte has arrived (`AnyAvailable`);
+    /// otherwise blocks until `size` bytes are read or the deadline passes (`AllOrNothing`).
+    #[func]
+    pub fn read_exact_timed(&mut self, size: u32, any_available: bool) -> PackedByteArray {
+        match &mut self.port {
+            Some(port) => {
+                let mode = if any_available {
+                    timed_read::ReadMode::AnyAvailable
+                } else {
+                    timed_read::ReadMode::AllOrNothing
+                };
+
+                let mut buffer = vec![0u8; size as usize];
+                let filled = timed_read::read_exact_timed(
+                    port.as_mut(),
+                    &mut buffer,
+                    mode,
+                    self.timeout,
+                    self.per_byte_timeout_multiplier,
+                );
+                buffer.truncate(filled);
+                PackedByteArray::from(&buffer[..])
+            }
+            None => {
+                godot_error!("{}", ERR_PORT_NOT_OPEN);
+                PackedByteArray::new()
+            }
+        }
+    }
+
+    #[func]
+    #[inline]
+    pub fn set_command_markers(&mut self, start_marker: u8, end_marker: u8) {
+        self.protocol.start_marker = start_marker;
+        self.protocol.end_marker = end_marker;
+    }
+
+    #[func]
+    #[inline]
+    pub fn set_ack_token(&mut self, ack_token: PackedByteArray) {
+        self.protocol.ack_token = ack_token.to_vec();
+    }
+
+    /// Wrap `payload` between the configured start/end markers, send it, and
+    /// block until the matching ACK arrives or the port timeout passes.
+    #[func]
+    pub fn send_command(&mut self, payload: PackedByteArray) -> bool {
+        match &mut self.port {
+            Some(port) => {
+                let bytes = payload.to_vec();
+                match self.protocol.send_command(port.as_mut(), &bytes, self.timeout) {
+                    Ok(_) => true,
+                    Err(protocol::CommandError::NoAck) => {
+                        godot_error!("Command timed out waiting for ACK");
+                        false
+                    }
+                    Err(e) => {
+                        godot_error!("Command failed: {}", e);
+                        false
+                    }
+                }
+            }
+            None => {
+                godot_error!("{}", ERR_PORT_NOT_OPEN);
+                false
+            }
+        }
+    }
+
+    #[func]
+    #[inline]
+    pub fn set_line_framing_enabled(&mut self, enabled: bool) {
+        self.line_framing_enabled = enabled;
+        self.line_accum.clear();
+    }
+
+    /// Spawns a listener thread on a `try_clone()` of the port. Mutually
+    /// exclusive with `start_reader()`: both clone the same port and would
+    /// compete for incoming bytes, splitting them nondeterministically between
+    /// the two.
+    #[func]
+    pub fn start_listening(&mut self) -> bool {
+        if self.listen_handle.is_some() {
+            godot_error!("Listener thread already running");
+            return false;
+        }
+
+        let port = match &self.port {
+            Some(port) => port.as_ref(),
+            None => {
+                godot_error!("{}", ERR_PORT_NOT_OPEN);
+                return false;
+            }
+        };
+
+        let mut listen_port = match port.try_clone() {
+            Ok(cloned) => cloned,
+            Err(e) => {
+                godot_error!("Failed to clone port for listener thread: {}", e);
+                return false;
+            }
+        };
+
+        let (tx, rx) = mpsc::channel();
+        let stop_flag = Arc::new(AtomicBool::new(false));
+        let thread_stop_flag = stop_flag.clone();
+
+        let handle = std::thread::spawn(move || {
+            let mut buffer = [0u8; READLINE_BUFFER_SIZE];
+            while !thread_stop_flag.load(Ordering::Relaxed) {
+                match listen_port.read(&mut buffer) {
+                    // A 0-byte read with no error means EOF (e.g. the port was
+                    // unplugged/closed underneath us) - stop instead of busy-spinning.
+                    Ok(0) => break,
+                    Ok(n) => {
+                        if tx.send(buffer[..n].to_vec()).is_err() {
+                            return;
+                        }
+                    }
+                    Err(ref e)
+                        if e.kind() == io::ErrorKind::TimedOut || e.kind() == io::ErrorKind::WouldBlock =>
+                    {
+                        continue;
+                    }
+                    Err(_) => break,
+                }
+            }
+        });
+
+        self.listen_handle = Some(handle);
+        self.listen_rx = Some(rx);
+        self.listen_stop = Some(stop_flag);
+        true
+    }
+
+    #[func]
+    pub fn stop_listening(&mut self) {
+        if let Some(stop_flag) = self.listen_stop.take() {
+            stop_flag.store(true, Ordering::Relaxed);
+        }
+        if let Some(handle) = self.listen_handle.take() {
+            let _ = handle.join();
+        }
+        self.listen_rx = None;
+        self.line_accum.clear();
+    }
+
+    /// Drain bytes the listener thread has received, emitting `data_received` for
+    /// each chunk and `line_received` for each complete line when line framing is
+    /// enabled. Godot signals must be emitted from the main thread, so call this
+    /// once per frame (e.g. from `_process`) rather than polling the port directly.
+    #[func]
+    pub fn poll(&mut self) {
+        self.poll_port_watch();
+
+        let chunks: Vec<Vec<u8>> = match &self.listen_rx {
+            Some(rx) => rx.try_iter().collect(),
+            None => return,
+        };
+
+        for chunk in chunks {
+            self.base_mut()
+                .emit_signal("data_received", &[PackedByteArray::from(&chunk[..]).to_variant()]);
+
+            if self.line_framing_enabled {
+                self.line_accum.extend_from_slice(&chunk);
+                while let Some(pos) = self.line_accum.iter().position(|&b| b == b'\n') {
+                    let line_bytes: Vec<u8> = self.line_accum.drain(..=pos).collect();
+                    let text = String::from_utf8_lossy(&line_bytes[..line_bytes.len() - 1]);
+                    let text = text.trim_end_matches('\r');
+                    self.base_mut()
+                        .emit_signal("line_received", &[GString::from(text).to_variant()]);
+                }
+            }
+        }
+    }
+
+    #[func]
+    #[inline]
+    pub fn set_auto_reopen(&mut self, enabled: bool) {
+        self.auto_reopen = enabled;
+    }
+
+    /// Spawn a thread that polls `available_ports()` every `poll_ms` and reports
+    /// connect/disconnect events via `port_connected`/`port_disconnected` the next
+    /// time `poll()` runs.
+    #[func]
+    pub fn start_port_watch(&mut self, poll_ms: u32) -> bool {
+        if self.watch_handle.is_some() {
+            godot_error!("Port watch thread already running");
+            return false;
+        }
+
+        let poll_ms = if poll_ms == 0 { DEFAULT_PORT_WATCH_POLL_MS } else { poll_ms };
+        let stop_flag = Arc::new(AtomicBool::new(false));
+        let (handle, rx) = watch::spawn_watch_thread(poll_ms as u64, stop_flag.clone());
+
+        self.watch_handle = Some(handle);
+        self.watch_rx = Some(rx);
+        self.watch_stop = Some(stop_flag);
+        true
+    }
+
+    #[func]
+    pub fn stop_port_watch(&mut self) {
+        if let Some(stop_flag) = self.watch_stop.take() {
+            stop_flag.store(true, Ordering::Relaxed);
+        }
+        if let Some(handle) = self.watch_handle.take() {
+            let _ = handle.join();
+        }
+        self.watch_rx = None;
+    }
+
+    /// Drain events from the port watcher thread, emitting `port_connected` /
+    /// `port_disconnected` for each one. When `auto_reopen` is set and the
+    /// currently-configured `port_name` reappears while the port is closed,
+    /// reopens it.
+    fn poll_port_watch(&mut self) {
+        let events: Vec<watch::PortEvent> = match &self.watch_rx {
+            Some(rx) => rx.try_iter().collect(),
+            None => return,
+        };
+
+        for event in events {
+            match event {
+                watch::PortEvent::Connected { port_name, port_type, device_name } => {
+                    let should_reopen = self.auto_reopen && self.port.is_none() && port_name == self.port_name;
+
+                    let mut info = Dictionary::new();
+                    info.set(KEY_PORT_NAME, port_name.as_str());
+                    info.set(KEY_PORT_TYPE, port_type.as_str());
+                    info.set(KEY_DEVICE_NAME, device_name.as_str());
+                    self.base_mut().emit_signal("port_connected", &[info.to_variant()]);
+
+                    if should_reopen {
+                        self.open();
+                    }
+                }
+                watch::PortEvent::Disconnected { port_name } => {
+                    self.base_mut()
+                        .emit_signal("port_disconnected", &[GString::from(port_name).to_variant()]);
+                }
+            }
+        }
+    }
+
+    #[func]
+    pub fn set_dtr(&mut self, level: bool) -> bool {
+        match &mut self.port {
+            Some(port) => match port.write_data_terminal_ready(level) {
+                Ok(_) => true,
+                Err(e) => {
+                    self.handle_potential_disconnection(&e);
+                    godot_error!("Failed to set DTR: {}", e);
+                    false
+                }
+            },
+            None => {
+                godot_error!("{}", ERR_PORT_NOT_OPEN);
+                false
+            }
+        }
+    }
+
+    #[func]
+    pub fn set_rts(&mut self, level: bool) -> bool {
+        match &mut self.port {
+            Some(port) => match port.write_request_to_send(level) {
+                Ok(_) => true,
+                Err(e) => {
+                    self.handle_potential_disconnection(&e);
+                    godot_error!("Failed to set RTS: {}", e);
+                    false
+                }
+            },
+            None => {
+                godot_error!("{}", ERR_PORT_NOT_OPEN);
+                false
+            }
+        }
+    }
+
+    #[func]
+    pub fn get_cts(&mut self) -> bool {
+        match &mut self.port {
+            Some(port) => match port.read_clear_to_send() {
+                Ok(level) => level,
+                Err(e) => {
+                    self.handle_potential_disconnection(&e);
+                    godot_error!("Failed to read CTS: {}", e);
+                    false
+                }
+            },
+            None => {
+                godot_error!("{}", ERR_PORT_NOT_OPEN);
+                false
+            }
+        }
+    }
+
+    #[func]
+    pub fn get_dsr(&mut self) -> bool {
+        match &mut self.port {
+            Some(port) => match port.read_data_set_ready() {
+                Ok(level) => level,
+                Err(e) => {
+                    self.handle_potential_disconnection(&e);
+                    godot_error!("Failed to read DSR: {}", e);
+                    false
+                }
+            },
+            None => {
+                godot_error!("{}", ERR_PORT_NOT_OPEN);
+                false
+            }
+        }
+    }
+
+    #[func]
+    pub fn get_cd(&mut self) -> bool {
+        match &mut self.port {
+            Some(port) => match port.read_carrier_detect() {
+                Ok(level) => level,
+                Err(e) => {
+                    self.handle_potential_disconnection(&e);
+                    godot_error!("Failed to read carrier detect: {}", e);
+                    false
+                }
+            },
+            None => {
+                godot_error!("{}", ERR_PORT_NOT_OPEN);
+                false
+            }
+        }
+    }
+
+    #[func]
+    pub fn get_ri(&mut self) -> bool {
+        match &mut self.port {
+            Some(port) => match port.read_ring_indicator() {
+                Ok(level) => level,
+                Err(e) => {
+                    self.handle_potential_disconnection(&e);
+                    godot_error!("Failed to read ring indicator: {}", e);
+                    false
+                }
+            },
+            None => {
+                godot_error!("{}", ERR_PORT_NOT_OPEN);
+                false
+            }
+        }
+    }
+
+    #[func]
+    pub fn set_break(&mut self, enabled: bool) -> bool {
+        match &mut self.port {
+            Some(port) => {
+                let result = if enabled { port.set_break() } else { port.clear_break() };
+                match result {
+                    Ok(_) => true,
+                    Err(e) => {
+                        self.handle_potential_disconnection(&e);
+                        godot_error!("Failed to set break: {}", e);
+                        false
+                    }
+                }
+            }
+            None => {
+                godot_error!("{}", ERR_PORT_NOT_OPEN);
+                false
+            }
+        }
+    }
+
+    /// Toggle DTR low then high, the standard Arduino/Pico firmware-reset gesture.
+    #[func]
+    pub fn pulse_dtr(&mut self, ms: u32) -> bool {
+        if !self.set_dtr(false) {
+            return false;
+        }
+        std::thread::sleep(Duration::from_millis(ms as u64));
+        self.set_dtr(true)
+    }
 }
\ No newline at end of file
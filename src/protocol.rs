@@ -0,0 +1,131 @@
+//! Framed request/response protocol with a start/end marker and ACK handshake.
+//!
+//! Firmware such as the MacroBoard wraps each message between a start marker and
+//! an end marker and expects an ACK frame before the next message is sent. This
+//! module turns that into a small state machine: wrap and transmit a command,
+//! then block (with a timeout) until a matching ACK is parsed from the inbound
+//! stream, discarding any noise bytes seen before the ACK's start marker.
+
+use serialport::SerialPort;
+use std::fmt;
+use std::io;
+use std::time::{Duration, Instant};
+
+/// Errors from a single command/response transaction.
+#[derive(Debug)]
+pub enum CommandError {
+    /// No matching ACK frame arrived before the timeout elapsed.
+    NoAck,
+    /// The underlying serial I/O failed.
+    Io(io::Error),
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommandError::NoAck => write!(f, "no ACK received before timeout"),
+            CommandError::Io(e) => write!(f, "I/O error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for CommandError {}
+
+impl From<io::Error> for CommandError {
+    fn from(e: io::Error) -> Self {
+        CommandError::Io(e)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Idle,
+    WaitingForAck,
+}
+
+/// Wraps payloads in a start/end marker and waits for an ACK frame before
+/// allowing the next command. Delimiters and the ACK token are plain fields so
+/// callers can adapt the protocol to their firmware.
+pub struct CommandProtocol {
+    pub start_marker: u8,
+    pub end_marker: u8,
+    pub ack_token: Vec<u8>,
+    state: State,
+}
+
+impl CommandProtocol {
+    pub fn new(start_marker: u8, end_marker: u8, ack_token: Vec<u8>) -> Self {
+        Self {
+            start_marker,
+            end_marker,
+            ack_token,
+            state: State::Idle,
+        }
+    }
+
+    /// True while a `send_command()` call is blocked waiting for its ACK.
+    pub fn is_waiting_for_ack(&self) -> bool {
+        self.state == State::WaitingForAck
+    }
+
+    /// Wrap `payload` in the start/end markers, transmit it on `port`, then
+    /// block up to `timeout` until a matching ACK frame is parsed.
+    pub fn send_command(
+        &mut self,
+        port: &mut dyn SerialPort,
+        payload: &[u8],
+        timeout: Duration,
+    ) -> Result<(), CommandError> {
+        let mut frame = Vec::with_capacity(payload.len() + 2);
+        frame.push(self.start_marker);
+        frame.extend_from_slice(payload);
+        frame.push(self.end_marker);
+        port.write_all(&frame)?;
+        port.flush()?;
+
+        self.state = State::WaitingForAck;
+        let result = self.wait_for_ack(port, timeout);
+        self.state = State::Idle;
+        result
+    }
+
+    /// Scan incoming bytes for the start marker followed by the ACK token,
+    /// discarding any unmatched/garbage bytes seen before it.
+    fn wait_for_ack(&self, port: &mut dyn SerialPort, timeout: Duration) -> Result<(), CommandError> {
+        let start = Instant::now();
+        let mut seen_start = false;
+        let mut matched = 0usize;
+        let mut byte = [0u8; 1];
+
+        while start.elapsed() < timeout {
+            match port.read(&mut byte) {
+                Ok(0) => continue,
+                Ok(_) => {
+                    let b = byte[0];
+                    if !seen_start {
+                        seen_start = b == self.start_marker;
+                        continue;
+                    }
+
+                    if self.ack_token.get(matched) == Some(&b) {
+                        matched += 1;
+                        if matched == self.ack_token.len() {
+                            return Ok(());
+                        }
+                    } else {
+                        matched = 0;
+                        seen_start = b == self.start_marker;
+                    }
+                }
+                Err(ref e)
+                    if e.kind() == io::ErrorKind::TimedOut || e.kind() == io::ErrorKind::WouldBlock =>
+                {
+                    continue;
+                }
+                Err(e) => return Err(CommandError::Io(e)),
+            }
+        }
+
+        Err(CommandError::NoAck)
+    }
+}
@@ -0,0 +1,134 @@
+//! A persistent, delimiter-scanning byte buffer for frame-at-a-time reads.
+//!
+//! Modeled on embassy's wrapping `start`/`end`/`empty` ring buffer: bytes pushed
+//! in accumulate past whatever has already been consumed, so a read that times
+//! out mid-frame keeps its partial data instead of losing it, and the next call
+//! picks up scanning right where the last one left off.
+
+/// Which byte (or byte pair) terminates a line for `readline()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineEnding {
+    Lf,
+    CrLf,
+    Cr,
+    Custom(u8),
+}
+
+impl LineEnding {
+    /// The single byte `RingBuffer::read_until` should scan for.
+    pub fn delimiter(self) -> u8 {
+        match self {
+            LineEnding::Lf | LineEnding::CrLf => b'\n',
+            LineEnding::Cr => b'\r',
+            LineEnding::Custom(b) => b,
+        }
+    }
+}
+
+/// Accumulates bytes across calls and hands back complete, delimiter-framed
+/// chunks as they become available.
+#[derive(Debug, Default)]
+pub struct RingBuffer {
+    data: Vec<u8>,
+    start: usize,
+}
+
+impl RingBuffer {
+    pub fn new() -> Self {
+        Self { data: Vec::new(), start: 0 }
+    }
+
+    /// Append newly-read bytes, compacting already-consumed bytes out first.
+    pub fn push(&mut self, bytes: &[u8]) {
+        if self.start > 0 {
+            if self.start == self.data.len() {
+                self.data.clear();
+            } else {
+                self.data.drain(..self.start);
+            }
+            self.start = 0;
+        }
+        self.data.extend_from_slice(bytes);
+    }
+
+    /// Scan the unread portion for `delimiter`, returning the frame before it
+    /// (delimiter excluded) and advancing past it. Returns `None` if no
+    /// delimiter has arrived yet, leaving the bytes buffered for next time. If
+    /// more than `max_len` bytes have accumulated with no delimiter in sight,
+    /// they're dropped so a stream that never frames can't grow forever.
+    pub fn read_until(&mut self, delimiter: u8, max_len: usize) -> Option<Vec<u8>> {
+        let unread = &self.data[self.start..];
+        match unread.iter().position(|&b| b == delimiter) {
+            Some(pos) => {
+                let frame = unread[..pos].to_vec();
+                self.start += pos + 1;
+                Some(frame)
+            }
+            None => {
+                if unread.len() > max_len {
+                    self.start = self.data.len();
+                }
+                None
+            }
+        }
+    }
+
+    /// Drop all buffered bytes, consumed or not.
+    pub fn clear(&mut self) {
+        self.data.clear();
+        self.start = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn returns_none_until_delimiter_arrives() {
+        let mut ring = RingBuffer::new();
+        ring.push(b"partial");
+        assert_eq!(ring.read_until(b'\n', 64), None);
+        ring.push(b" frame\n");
+        assert_eq!(ring.read_until(b'\n', 64), Some(b"partial frame".to_vec()));
+    }
+
+    #[test]
+    fn leaves_leftover_bytes_buffered_for_next_call() {
+        let mut ring = RingBuffer::new();
+        ring.push(b"one\ntwo\nthr");
+        assert_eq!(ring.read_until(b'\n', 64), Some(b"one".to_vec()));
+        assert_eq!(ring.read_until(b'\n', 64), Some(b"two".to_vec()));
+        assert_eq!(ring.read_until(b'\n', 64), None);
+        ring.push(b"ee\n");
+        assert_eq!(ring.read_until(b'\n', 64), Some(b"three".to_vec()));
+    }
+
+    #[test]
+    fn timeout_mid_frame_does_not_lose_data() {
+        let mut ring = RingBuffer::new();
+        ring.push(b"abc");
+        assert_eq!(ring.read_until(b'\n', 64), None); // simulated timeout, no delimiter yet
+        ring.push(b"def\n");
+        assert_eq!(ring.read_until(b'\n', 64), Some(b"abcdef".to_vec()));
+    }
+
+    #[test]
+    fn drops_unframed_data_past_max_len() {
+        let mut ring = RingBuffer::new();
+        ring.push(&[0u8; 8]);
+        assert_eq!(ring.read_until(b'\n', 4), None);
+        // Oversized, undelimited run was dropped; a fresh frame still works.
+        ring.push(b"ok\n");
+        assert_eq!(ring.read_until(b'\n', 4), Some(b"ok".to_vec()));
+    }
+
+    #[test]
+    fn clear_discards_buffered_bytes() {
+        let mut ring = RingBuffer::new();
+        ring.push(b"stale");
+        ring.clear();
+        ring.push(b"fresh\n");
+        assert_eq!(ring.read_until(b'\n', 64), Some(b"fresh".to_vec()));
+    }
+}
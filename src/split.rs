@@ -0,0 +1,53 @@
+//! Split a serial port into independent read/write halves for full-duplex use.
+//!
+//! A single `Box<dyn SerialPort>` held behind one mutable handle forces readers and
+//! writers to take turns. `split()` hands out two independent handles, each backed by
+//! its own `try_clone()` of the underlying port, so a reader thread and a writer
+//! thread can operate on the same serial connection simultaneously.
+
+use serialport::SerialPort;
+use std::io::{self, Read, Write};
+
+/// The read half of a split serial port.
+pub struct SerialReader {
+    port: Box<dyn SerialPort>,
+}
+
+impl SerialReader {
+    pub fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.port.read(buf)
+    }
+
+    pub fn bytes_to_read(&self) -> serialport::Result<u32> {
+        self.port.bytes_to_read()
+    }
+}
+
+/// The write half of a split serial port.
+pub struct SerialWriter {
+    port: Box<dyn SerialPort>,
+}
+
+impl SerialWriter {
+    pub fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.port.write(buf)
+    }
+
+    pub fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.port.write_all(buf)
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.port.flush()
+    }
+}
+
+/// Split `port` into independent reader/writer halves, each backed by `try_clone()`.
+pub fn split(port: &dyn SerialPort) -> serialport::Result<(SerialReader, SerialWriter)> {
+    let reader_port = port.try_clone()?;
+    let writer_port = port.try_clone()?;
+    Ok((
+        SerialReader { port: reader_port },
+        SerialWriter { port: writer_port },
+    ))
+}
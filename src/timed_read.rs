@@ -0,0 +1,66 @@
+//! Read-semantics helpers distinguishing "timeout means no data" from "timeout
+//! means partial data", ported from the read-mode model used by the D
+//! `serialport` library.
+
+use serialport::SerialPort;
+use std::io;
+use std::time::{Duration, Instant};
+
+/// How a timed read should behave once the requested length isn't immediately available.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadMode {
+    /// Only return once `buf` is completely filled, or the deadline passes.
+    AllOrNothing,
+    /// Return as soon as at least one byte has been read.
+    AnyAvailable,
+}
+
+/// Read into `buf` under `mode`, allowing up to
+/// `base_timeout + buf.len() * per_byte_multiplier` total time so large reads at
+/// low baud rates get proportionally more time than a single fixed timeout allows.
+///
+/// Returns the number of bytes actually filled, which may be less than
+/// `buf.len()` if the deadline passes before enough data arrives.
+pub fn read_exact_timed(
+    port: &mut dyn SerialPort,
+    buf: &mut [u8],
+    mode: ReadMode,
+    base_timeout: Duration,
+    per_byte_multiplier: Duration,
+) -> usize {
+    let original_timeout = port.timeout();
+    let deadline = base_timeout + per_byte_multiplier * buf.len() as u32;
+    let start = Instant::now();
+    let mut filled = 0;
+
+    while filled < buf.len() {
+        let elapsed = start.elapsed();
+        if elapsed >= deadline {
+            break;
+        }
+
+        let remaining = deadline - elapsed;
+        if port.set_timeout(remaining).is_err() {
+            break;
+        }
+
+        match port.read(&mut buf[filled..]) {
+            Ok(0) => continue,
+            Ok(n) => {
+                filled += n;
+                if mode == ReadMode::AnyAvailable {
+                    break;
+                }
+            }
+            Err(ref e)
+                if e.kind() == io::ErrorKind::TimedOut || e.kind() == io::ErrorKind::WouldBlock =>
+            {
+                continue;
+            }
+            Err(_) => break,
+        }
+    }
+
+    let _ = port.set_timeout(original_timeout);
+    filled
+}
@@ -0,0 +1,191 @@
+//! USB hotplug monitoring by polling `available_ports()` on an interval.
+//!
+//! `serialport` has no event-driven connect/disconnect notification, so this
+//! module periodically re-lists ports and diffs the set of `port_name`s against
+//! the previous snapshot, the same way a USB host notices device attach/detach
+//! via polling when it has no hotplug interrupt to rely on. Events are sent as
+//! plain data across a channel; `poll()` converts them into Godot types and
+//! emits `port_connected`/`port_disconnected` on the main thread.
+
+use serialport::{SerialPortInfo, SerialPortType, UsbPortInfo};
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// How often the watch thread re-checks the stop flag while sleeping between polls.
+const WATCH_POLL_GRANULARITY_MS: u64 = 50;
+
+/// A connect/disconnect event reported by the watcher thread.
+pub enum PortEvent {
+    Connected {
+        port_name: String,
+        port_type: String,
+        device_name: String,
+    },
+    Disconnected {
+        port_name: String,
+    },
+}
+
+/// Borrow the USB descriptors of `port`, or `None` for non-USB ports.
+pub fn usb_info(port: &SerialPortInfo) -> Option<&UsbPortInfo> {
+    match &port.port_type {
+        SerialPortType::UsbPort(usb_info) => Some(usb_info),
+        _ => None,
+    }
+}
+
+/// Classify a `SerialPortInfo` into `(port_type, device_name)` strings, the same
+/// way `list_ports()` does, so connect events carry a usable description.
+pub fn classify_port(port: &SerialPortInfo) -> (String, String) {
+    match &port.port_type {
+        SerialPortType::UsbPort(usb_info) => {
+            let port_type = format!("USB - VID: {:04X}, PID: {:04X}", usb_info.vid, usb_info.pid);
+            let device_name = crate::get_usb_device_name(
+                usb_info.vid,
+                usb_info.pid,
+                &usb_info.manufacturer,
+                &usb_info.product,
+            );
+            (port_type, device_name)
+        }
+        SerialPortType::PciPort => (crate::PORT_TYPE_PCI.to_string(), crate::DEVICE_NAME_PCI.to_string()),
+        SerialPortType::BluetoothPort => {
+            (crate::PORT_TYPE_BLUETOOTH.to_string(), crate::DEVICE_NAME_BLUETOOTH.to_string())
+        }
+        SerialPortType::Unknown => (crate::PORT_TYPE_UNKNOWN.to_string(), crate::DEVICE_NAME_UNKNOWN.to_string()),
+    }
+}
+
+/// Diff `current` ports against the `previous` snapshot of port names, returning
+/// the connect/disconnect events and the updated snapshot.
+pub fn diff_ports(previous: &HashSet<String>, current: &[SerialPortInfo]) -> (Vec<PortEvent>, HashSet<String>) {
+    let mut next = HashSet::with_capacity(current.len());
+    let mut events = Vec::new();
+
+    for port in current {
+        next.insert(port.port_name.clone());
+        if !previous.contains(&port.port_name) {
+            let (port_type, device_name) = classify_port(port);
+            events.push(PortEvent::Connected {
+                port_name: port.port_name.clone(),
+                port_type,
+                device_name,
+            });
+        }
+    }
+
+    for port_name in previous {
+        if !next.contains(port_name) {
+            events.push(PortEvent::Disconnected {
+                port_name: port_name.clone(),
+            });
+        }
+    }
+
+    (events, next)
+}
+
+/// Spawn a thread that calls `available_ports()` every `poll_ms` milliseconds,
+/// sending a `PortEvent` for each addition/removal until `stop_flag` is set.
+pub fn spawn_watch_thread(poll_ms: u64, stop_flag: Arc<AtomicBool>) -> (JoinHandle<()>, Receiver<PortEvent>) {
+    let (tx, rx) = mpsc::channel();
+
+    let handle = std::thread::spawn(move || {
+        // Seed the snapshot with whatever is already plugged in so the first
+        // diff only reports genuine attach/detach events, not every port that
+        // was present when watching started.
+        let mut known: HashSet<String> = serialport::available_ports()
+            .map(|ports| ports.into_iter().map(|p| p.port_name).collect())
+            .unwrap_or_default();
+
+        while !stop_flag.load(Ordering::Relaxed) {
+            if let Ok(ports) = serialport::available_ports() {
+                let (events, next) = diff_ports(&known, &ports);
+                known = next;
+                for event in events {
+                    if tx.send(event).is_err() {
+                        return;
+                    }
+                }
+            }
+
+            let mut slept = 0u64;
+            while slept < poll_ms && !stop_flag.load(Ordering::Relaxed) {
+                let step = WATCH_POLL_GRANULARITY_MS.min(poll_ms - slept);
+                std::thread::sleep(Duration::from_millis(step));
+                slept += step;
+            }
+        }
+    });
+
+    (handle, rx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usb_port(name: &str, vid: u16, pid: u16) -> SerialPortInfo {
+        SerialPortInfo {
+            port_name: name.to_string(),
+            port_type: SerialPortType::UsbPort(serialport::UsbPortInfo {
+                vid,
+                pid,
+                serial_number: None,
+                manufacturer: None,
+                product: None,
+                #[cfg(any(target_os = "linux", target_os = "android"))]
+                interface: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn empty_snapshot_reports_every_port_as_connected() {
+        let previous = HashSet::new();
+        let current = vec![usb_port("COM3", 0x2341, 0x0043)];
+        let (events, next) = diff_ports(&previous, &current);
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(&events[0], PortEvent::Connected { port_name, .. } if port_name == "COM3"));
+        assert!(next.contains("COM3"));
+    }
+
+    #[test]
+    fn unchanged_ports_report_no_events() {
+        let mut previous = HashSet::new();
+        previous.insert("COM3".to_string());
+        let current = vec![usb_port("COM3", 0x2341, 0x0043)];
+        let (events, _) = diff_ports(&previous, &current);
+
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn removed_port_reports_disconnected() {
+        let mut previous = HashSet::new();
+        previous.insert("COM3".to_string());
+        let current: Vec<SerialPortInfo> = vec![];
+        let (events, next) = diff_ports(&previous, &current);
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(&events[0], PortEvent::Disconnected { port_name } if port_name == "COM3"));
+        assert!(next.is_empty());
+    }
+
+    #[test]
+    fn added_and_removed_ports_report_together() {
+        let mut previous = HashSet::new();
+        previous.insert("COM3".to_string());
+        let current = vec![usb_port("COM4", 0x2341, 0x0043)];
+        let (events, next) = diff_ports(&previous, &current);
+
+        assert_eq!(events.len(), 2);
+        assert!(next.contains("COM4"));
+        assert!(!next.contains("COM3"));
+    }
+}